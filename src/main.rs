@@ -8,13 +8,14 @@ use std::{
 
 use bevy::{
     prelude::*,
-    window::{CursorOptions, PrimaryWindow, WindowLevel, WindowMode},
+    window::{CursorOptions, Monitor, PrimaryWindow, WindowLevel, WindowMode},
     winit::WinitSettings,
 };
 
 #[cfg(target_os = "macos")]
 use bevy::window::CompositeAlphaMode;
-use mki::{Action, InhibitEvent, Mouse};
+use mki::{Action, InhibitEvent, Keyboard, Mouse};
+use serde::Deserialize;
 
 fn main() {
     let window = Window {
@@ -38,7 +39,14 @@ fn main() {
             ..default()
         }))
         .init_resource::<IndicatorAssets>()
+        .init_resource::<Bindings>()
         .init_resource::<GlobalMouseEventQueue>()
+        .init_resource::<MonitorLayout>()
+        .init_resource::<CursorPosition>()
+        .init_resource::<SmoothedCursor>()
+        .init_resource::<PendingMoveSamples>()
+        .init_resource::<FollowConfig>()
+        .init_resource::<TrailSpawnTimer>()
         .insert_resource(WinitSettings {
             focused_mode: bevy::winit::UpdateMode::Reactive {
                 wait: Duration::from_millis(100),
@@ -51,7 +59,16 @@ fn main() {
         .add_systems(Startup, setup)
         .add_systems(
             Update,
-            monitor_event_queue.run_if(resource_exists::<GlobalMouseEventQueue>),
+            (
+                refresh_monitor_layout,
+                monitor_event_queue.run_if(resource_exists::<GlobalMouseEventQueue>),
+                follow_cursor,
+                spawn_trail,
+                update_trail_particles,
+                fade_scroll_indicator,
+                animate_indicators,
+            )
+                .chain(),
         )
         .run();
 }
@@ -59,6 +76,7 @@ fn main() {
 fn setup(
     mut commands: Commands,
     indicators: Res<IndicatorAssets>,
+    bindings: Res<Bindings>,
     mut windows: Query<&mut Window, With<PrimaryWindow>>,
 ) {
     commands.spawn(Camera2d);
@@ -68,60 +86,570 @@ fn setup(
             WindowMode::Fullscreen(MonitorSelection::Current, VideoModeSelection::Current);
     }
 
-    commands.spawn((MouseIndicator(true), indicators.left(), Visibility::Hidden));
+    for binding in &bindings.0 {
+        commands.spawn((
+            MouseIndicator(binding.action.clone()),
+            ActionHeld(false),
+            IndicatorSpriteRect(binding.rect),
+            IndicatorAnimation::default(),
+            indicators.sprite_for(binding.rect),
+            Visibility::Hidden,
+        ));
+    }
+
     commands.spawn((
-        MouseIndicator(false),
-        indicators.right(),
+        ScrollIndicator::default(),
+        indicators.scroll(),
         Visibility::Hidden,
     ));
 }
 
 fn monitor_event_queue(
     queue: ResMut<GlobalMouseEventQueue>,
-    mut indicators: Query<(&MouseIndicator, &mut Visibility, &mut Transform)>,
+    layout: Res<MonitorLayout>,
+    mut cursor: ResMut<CursorPosition>,
+    smoothed: Res<SmoothedCursor>,
+    mut pending_moves: ResMut<PendingMoveSamples>,
+    mut winit_settings: ResMut<WinitSettings>,
+    mut indicators: Query<(
+        &MouseIndicator,
+        &mut ActionHeld,
+        &mut Visibility,
+        &mut IndicatorAnimation,
+    )>,
+    mut scroll_indicator: Query<
+        (&mut ScrollIndicator, &mut Visibility, &mut Transform),
+        Without<MouseIndicator>,
+    >,
 ) {
     if let Ok(mut queue) = queue.0.write() {
         while let Some(event) = queue.pop_front() {
-            for (indicator, mut visibility, mut tx) in &mut indicators {
-                match event {
-                    MouseEvent::LeftDown => {
-                        trace!("Handling spawn left");
-                        if indicator.0 {
+            match &event {
+                MouseEvent::ActionDown(action) => {
+                    trace!("Handling spawn {action:?}");
+                    for (indicator, mut held, mut visibility, mut anim) in &mut indicators {
+                        if indicator.0 == *action {
+                            held.0 = true;
                             *visibility = Visibility::Visible;
+                            anim.start(IndicatorAnimState::Press);
                         }
                     }
-                    MouseEvent::LeftUp => {
-                        trace!("Handling despawn left");
-
-                        if indicator.0 {
-                            *visibility = Visibility::Hidden;
+                }
+                MouseEvent::ActionUp(action) => {
+                    trace!("Handling despawn {action:?}");
+                    for (indicator, mut held, _, mut anim) in &mut indicators {
+                        if indicator.0 == *action {
+                            held.0 = false;
+                            // Visibility is hidden once the release frame
+                            // finishes playing, in `animate_indicators`.
+                            anim.start(IndicatorAnimState::Release);
                         }
                     }
-                    MouseEvent::RightDown => {
-                        trace!("Handling spawn right");
-
-                        if !indicator.0 {
-                            *visibility = Visibility::Visible;
-                        }
+                }
+                MouseEvent::MouseMove(x, y) => {
+                    trace!("Moving icon to {x}, {y}");
+                    let global = IVec2::new(*x, *y);
+                    let on_screen = layout.contains_any(global);
+                    if on_screen != cursor.on_screen {
+                        cursor.on_screen = on_screen;
+                        // Queued immediately behind the remaining samples in
+                        // this batch, so it's handled in the same drain.
+                        queue.push_back(if on_screen {
+                            MouseEvent::CursorEntered
+                        } else {
+                            MouseEvent::CursorLeft
+                        });
                     }
-                    MouseEvent::RightUp => {
-                        trace!("Handling despawn right");
-
-                        if !indicator.0 {
-                            *visibility = Visibility::Hidden;
-                        }
+                    // Record every sample in this batch, not just the last,
+                    // so a burst of `MouseMove` events queued within one
+                    // frame isn't collapsed into a single jump; `follow_cursor`
+                    // walks through all of them instead of teleporting here.
+                    cursor.world = layout.to_world(global);
+                    pending_moves.0.push(cursor.world);
+                }
+                MouseEvent::Scroll(_dx, dy) => {
+                    trace!("Handling scroll {dy}");
+                    if let Ok((mut scroll, mut visibility, mut tx)) = scroll_indicator.single_mut()
+                    {
+                        scroll.accumulated = (scroll.accumulated + *dy as f32)
+                            .clamp(-SCROLL_OFFSET_MAX, SCROLL_OFFSET_MAX);
+                        scroll.idle.reset();
+                        *visibility = Visibility::Visible;
+                        tx.translation.x = smoothed.0.x;
+                        tx.translation.y = smoothed.0.y + scroll.accumulated;
+                    }
+                }
+                MouseEvent::CursorLeft => {
+                    trace!("Cursor left the covered monitors, suspending overlay");
+                    for (_, _, mut visibility, _) in &mut indicators {
+                        *visibility = Visibility::Hidden;
+                    }
+                    if let Ok((_, mut visibility, _)) = scroll_indicator.single_mut() {
+                        *visibility = Visibility::Hidden;
                     }
-                    MouseEvent::MouseMove(x, y) => {
-                        trace!("Moving icon to {x}, {y}");
-                        tx.translation.x = (x - 2560 / 2) as f32;
-                        tx.translation.y = -(y - 1440 / 2) as f32;
+                    winit_settings.unfocused_mode = bevy::winit::UpdateMode::Reactive {
+                        wait: Duration::from_millis(250),
+                        react_to_device_events: true,
+                        react_to_user_events: true,
+                        react_to_window_events: true,
+                    };
+                }
+                MouseEvent::CursorEntered => {
+                    trace!("Cursor re-entered a covered monitor, resuming overlay");
+                    // Re-show any indicator whose action is still held, since
+                    // `CursorLeft` force-hid it without touching `ActionHeld`.
+                    for (_, held, mut visibility, _) in &mut indicators {
+                        if held.0 {
+                            *visibility = Visibility::Visible;
+                        }
                     }
+                    winit_settings.unfocused_mode = bevy::winit::UpdateMode::Continuous;
                 }
             }
         }
     }
 }
 
+/// The eased cursor position every `MouseIndicator` is placed at,
+/// updated once per frame by [`follow_cursor`] rather than on every queued
+/// `MouseMove` sample.
+#[derive(Resource, Default)]
+struct SmoothedCursor(Vec2);
+
+/// World-space targets queued by [`monitor_event_queue`] since the last time
+/// [`follow_cursor`] ran. A single frame can drain several `MouseMove`
+/// samples off the hook queue (e.g. after a stutter), and every one of them
+/// should nudge the trail, not just the last.
+#[derive(Resource, Default)]
+struct PendingMoveSamples(Vec<Vec2>);
+
+/// Configures how smoothly indicators chase the cursor and whether a fading
+/// motion trail is left behind along the way.
+#[derive(Resource, Debug, Clone, Copy)]
+struct FollowConfig {
+    /// Exponential decay rate, per second: each step closes
+    /// `1 - e^(-smoothing * dt)` of the remaining gap. Higher values track
+    /// the cursor more tightly.
+    smoothing: f32,
+    trail: TrailConfig,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TrailConfig {
+    enabled: bool,
+    /// Seconds between spawning a new trail sprite.
+    spawn_interval: f32,
+    /// Seconds a trail sprite lives before despawning.
+    lifetime: f32,
+}
+
+impl Default for FollowConfig {
+    fn default() -> Self {
+        Self {
+            smoothing: 18.0,
+            trail: TrailConfig {
+                enabled: true,
+                spawn_interval: 0.03,
+                lifetime: 0.25,
+            },
+        }
+    }
+}
+
+/// Lerps [`SmoothedCursor`] toward the cursor and places every
+/// `MouseIndicator` at it instead of teleporting it, using an exponential
+/// ease so the motion looks the same regardless of frame rate.
+///
+/// Drains [`PendingMoveSamples`] rather than jumping straight to
+/// [`CursorPosition::world`], so every queued `MouseMove` sample from this
+/// frame gets a fair share of the smoothing step instead of only the latest
+/// one. With no samples queued (cursor idle), it still eases the trail
+/// toward the last known target so motion doesn't freeze mid-lerp.
+fn follow_cursor(
+    time: Res<Time>,
+    config: Res<FollowConfig>,
+    cursor: Res<CursorPosition>,
+    mut pending_moves: ResMut<PendingMoveSamples>,
+    mut smoothed: ResMut<SmoothedCursor>,
+    mut indicators: Query<&mut Transform, With<MouseIndicator>>,
+) {
+    let samples = std::mem::take(&mut pending_moves.0);
+    if samples.is_empty() {
+        let alpha = (1.0 - (-config.smoothing * time.delta_secs()).exp()).clamp(0.0, 1.0);
+        smoothed.0 = smoothed.0.lerp(cursor.world, alpha);
+    } else {
+        let step_dt = time.delta_secs() / samples.len() as f32;
+        let alpha = (1.0 - (-config.smoothing * step_dt).exp()).clamp(0.0, 1.0);
+        for sample in samples {
+            smoothed.0 = smoothed.0.lerp(sample, alpha);
+        }
+    }
+
+    for mut tx in &mut indicators {
+        tx.translation.x = smoothed.0.x;
+        tx.translation.y = smoothed.0.y;
+    }
+}
+
+/// Repeatedly ticks down to zero while [`FollowConfig::trail`] is enabled,
+/// spawning a new [`TrailParticle`] each time it fires.
+#[derive(Resource)]
+struct TrailSpawnTimer(Timer);
+
+impl FromWorld for TrailSpawnTimer {
+    fn from_world(world: &mut World) -> Self {
+        let interval = world.resource::<FollowConfig>().trail.spawn_interval;
+        Self(Timer::from_seconds(interval, TimerMode::Repeating))
+    }
+}
+
+/// A short-lived, fading sprite left behind along the smoothed cursor path.
+#[derive(Component)]
+struct TrailParticle {
+    lifetime: Timer,
+}
+
+fn spawn_trail(
+    time: Res<Time>,
+    config: Res<FollowConfig>,
+    smoothed: Res<SmoothedCursor>,
+    indicators: Res<IndicatorAssets>,
+    mut timer: ResMut<TrailSpawnTimer>,
+    mut commands: Commands,
+) {
+    if !config.trail.enabled {
+        return;
+    }
+
+    timer.0.tick(time.delta());
+    if !timer.0.just_finished() {
+        return;
+    }
+
+    commands.spawn((
+        TrailParticle {
+            lifetime: Timer::from_seconds(config.trail.lifetime, TimerMode::Once),
+        },
+        indicators.trail(),
+        Transform::from_translation(smoothed.0.extend(0.0)),
+    ));
+}
+
+fn update_trail_particles(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut particles: Query<(Entity, &mut TrailParticle, &mut Sprite)>,
+) {
+    for (entity, mut particle, mut sprite) in &mut particles {
+        particle.lifetime.tick(time.delta());
+        sprite.color = Color::srgba(1.0, 1.0, 1.0, particle.lifetime.fraction_remaining());
+
+        if particle.lifetime.finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Seconds the press ripple plays for after an `ActionDown`.
+const PRESS_ANIMATION_SECONDS: f32 = 0.18;
+/// Seconds the release frame plays for after an `ActionUp`, before hiding.
+const RELEASE_ANIMATION_SECONDS: f32 = 0.12;
+/// How many extra frames follow an indicator's base rect in `indicators.png`
+/// for the press ripple, laid out in rows directly below the action's own
+/// base rect (see [`IndicatorAssets::action_frame`]) rather than left-to-right,
+/// so one action's frames never overlap a neighbouring action's rect.
+const PRESS_FRAME_COUNT: usize = 3;
+
+fn ease_out_cubic(t: f32) -> f32 {
+    1.0 - (1.0 - t).powi(3)
+}
+
+/// Which click-feedback animation, if any, an indicator is currently
+/// playing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum IndicatorAnimState {
+    #[default]
+    Idle,
+    Press,
+    Release,
+}
+
+/// Drives the ripple/pulse feedback played when an indicator's action goes
+/// down or up, advanced each frame by [`animate_indicators`].
+#[derive(Component, Default)]
+struct IndicatorAnimation {
+    state: IndicatorAnimState,
+    elapsed: f32,
+}
+
+impl IndicatorAnimation {
+    fn start(&mut self, state: IndicatorAnimState) {
+        self.state = state;
+        self.elapsed = 0.0;
+    }
+}
+
+/// The base sprite rect an indicator returns to once its animation finishes,
+/// since `indicators.action_frame` needs it to locate the other frames.
+#[derive(Component, Clone, Copy)]
+struct IndicatorSpriteRect(IndicatorRect);
+
+/// Advances each indicator's [`IndicatorAnimation`], scaling and fading it
+/// through a ripple on press, cycling through the sprite sheet's extra
+/// frames, and playing a release frame before hiding on release.
+fn animate_indicators(
+    time: Res<Time>,
+    indicators: Res<IndicatorAssets>,
+    mut query: Query<(
+        &IndicatorSpriteRect,
+        &mut IndicatorAnimation,
+        &mut Sprite,
+        &mut Transform,
+        &mut Visibility,
+    )>,
+) {
+    for (rect, mut anim, mut sprite, mut tx, mut visibility) in &mut query {
+        let duration = match anim.state {
+            IndicatorAnimState::Idle => continue,
+            IndicatorAnimState::Press => PRESS_ANIMATION_SECONDS,
+            IndicatorAnimState::Release => RELEASE_ANIMATION_SECONDS,
+        };
+
+        anim.elapsed += time.delta_secs();
+        let t = (anim.elapsed / duration).clamp(0.0, 1.0);
+        let eased = ease_out_cubic(t);
+
+        match anim.state {
+            IndicatorAnimState::Press => {
+                let frame = ((eased * PRESS_FRAME_COUNT as f32) as usize)
+                    .min(PRESS_FRAME_COUNT.saturating_sub(1));
+                *sprite = indicators.action_frame(rect.0, frame);
+                tx.scale = Vec3::splat(1.0 + 0.4 * eased);
+                sprite.color.set_alpha(1.0 - 0.5 * eased);
+            }
+            IndicatorAnimState::Release => {
+                *sprite = indicators.action_frame(rect.0, PRESS_FRAME_COUNT.saturating_sub(1));
+                tx.scale = Vec3::splat(1.4 - 0.4 * eased);
+                sprite.color.set_alpha(0.5 + 0.5 * eased);
+            }
+            IndicatorAnimState::Idle => unreachable!(),
+        }
+
+        if t >= 1.0 {
+            if anim.state == IndicatorAnimState::Release {
+                *visibility = Visibility::Hidden;
+            }
+            anim.state = IndicatorAnimState::Idle;
+            tx.scale = Vec3::ONE;
+            *sprite = indicators.sprite_for(rect.0);
+        }
+    }
+}
+
+/// Ticks the scroll indicator's idle timer and fades its sprite out, hiding
+/// it once the timeout elapses. Runs every frame so the fade is smooth
+/// regardless of how often scroll events arrive.
+fn fade_scroll_indicator(
+    time: Res<Time>,
+    mut indicators: Query<(&mut ScrollIndicator, &mut Visibility, &mut Sprite)>,
+) {
+    for (mut scroll, mut visibility, mut sprite) in &mut indicators {
+        if *visibility == Visibility::Hidden {
+            continue;
+        }
+
+        scroll.idle.tick(time.delta());
+        sprite.color = Color::srgba(1.0, 1.0, 1.0, scroll.idle.fraction_remaining());
+
+        if scroll.idle.finished() {
+            *visibility = Visibility::Hidden;
+            scroll.accumulated = 0.0;
+        }
+    }
+}
+
+/// Tracks the desktop-space bounds of every connected monitor so that global
+/// cursor coordinates can be mapped onto the right window-local world
+/// position, regardless of monitor resolution or layout.
+#[derive(Resource, Default, Debug)]
+struct MonitorLayout {
+    monitors: Vec<MonitorGeometry>,
+}
+
+/// Deliberately doesn't carry the monitor's DPI scale factor: `mki`'s mouse
+/// hooks and `origin`/`size` below are both already in physical pixels
+/// (bevy's `Monitor::physical_position`/`physical_width`/`physical_height`),
+/// so there's no logical-to-physical conversion left for `contains`/
+/// `to_world` to apply, and a field nothing reads is just dead state.
+#[derive(Debug, Clone, Copy)]
+struct MonitorGeometry {
+    /// Top-left corner of the monitor in global desktop coordinates.
+    origin: IVec2,
+    /// Physical size of the monitor, already adjusted for scale factor.
+    size: IVec2,
+}
+
+impl MonitorGeometry {
+    fn contains(&self, global: IVec2) -> bool {
+        global.x >= self.origin.x
+            && global.x < self.origin.x + self.size.x
+            && global.y >= self.origin.y
+            && global.y < self.origin.y + self.size.y
+    }
+}
+
+impl MonitorLayout {
+    /// Whether `global` falls within the bounds of any known monitor. Used
+    /// to detect the cursor leaving every monitor the overlay covers.
+    fn contains_any(&self, global: IVec2) -> bool {
+        self.monitors.iter().any(|m| m.contains(global))
+    }
+
+    /// Converts a global desktop coordinate into a window-local world
+    /// position, centering on whichever monitor's bounds contain it. Falls
+    /// back to the first known monitor (or the raw coordinate, if none are
+    /// known yet) so the indicator still moves before the first refresh.
+    fn to_world(&self, global: IVec2) -> Vec2 {
+        let Some(monitor) = self
+            .monitors
+            .iter()
+            .find(|m| m.contains(global))
+            .or_else(|| self.monitors.first())
+        else {
+            return Vec2::new(global.x as f32, -(global.y as f32));
+        };
+
+        Vec2::new(
+            (global.x - monitor.origin.x - monitor.size.x / 2) as f32,
+            -(global.y - monitor.origin.y - monitor.size.y / 2) as f32,
+        )
+    }
+}
+
+#[cfg(test)]
+mod monitor_layout_tests {
+    use super::*;
+
+    fn monitor(origin: (i32, i32), size: (i32, i32)) -> MonitorGeometry {
+        MonitorGeometry {
+            origin: IVec2::new(origin.0, origin.1),
+            size: IVec2::new(size.0, size.1),
+        }
+    }
+
+    #[test]
+    fn to_world_centers_on_monitor_with_negative_origin() {
+        let layout = MonitorLayout {
+            monitors: vec![monitor((-1920, -200), (1920, 1080))],
+        };
+
+        // Dead center of the monitor maps to the world origin.
+        assert_eq!(layout.to_world(IVec2::new(-960, 340)), Vec2::ZERO);
+        // Top-left corner maps up-and-left of center, with y flipped.
+        assert_eq!(
+            layout.to_world(IVec2::new(-1920, -200)),
+            Vec2::new(-960.0, 540.0)
+        );
+    }
+
+    #[test]
+    fn to_world_falls_back_to_first_monitor_when_none_contain_the_point() {
+        let layout = MonitorLayout {
+            monitors: vec![monitor((0, 0), (1920, 1080))],
+        };
+
+        // Off every monitor's bounds, but a layout is known: falls back to
+        // the first monitor rather than the raw-coordinate escape hatch.
+        assert_eq!(
+            layout.to_world(IVec2::new(5000, 5000)),
+            Vec2::new(5000.0 - 960.0, -(5000.0 - 540.0))
+        );
+    }
+
+    #[test]
+    fn to_world_uses_raw_coordinates_when_no_monitors_are_known_yet() {
+        let layout = MonitorLayout { monitors: vec![] };
+
+        assert_eq!(layout.to_world(IVec2::new(42, 10)), Vec2::new(42.0, -10.0));
+    }
+
+    #[test]
+    fn contains_any_is_true_only_within_a_known_monitor() {
+        let layout = MonitorLayout {
+            monitors: vec![
+                monitor((0, 0), (1920, 1080)),
+                monitor((1920, 0), (1280, 1024)),
+            ],
+        };
+
+        assert!(layout.contains_any(IVec2::new(0, 0)));
+        assert!(layout.contains_any(IVec2::new(1920, 0)));
+        assert!(!layout.contains_any(IVec2::new(3200, 0)));
+        assert!(!layout.contains_any(IVec2::new(-1, 0)));
+    }
+}
+
+/// Rebuilds the [`MonitorLayout`] from the current `Monitor` entities every
+/// frame. Polling rather than reacting to a specific hot-plug event keeps
+/// this robust across platforms where monitor-change events aren't reliably
+/// delivered, at the cost of a cheap query each `Update`.
+fn refresh_monitor_layout(monitors: Query<&Monitor>, mut layout: ResMut<MonitorLayout>) {
+    layout.monitors = monitors
+        .iter()
+        .map(|monitor| MonitorGeometry {
+            origin: IVec2::new(monitor.physical_position.x, monitor.physical_position.y),
+            size: IVec2::new(
+                monitor.physical_width as i32,
+                monitor.physical_height as i32,
+            ),
+        })
+        .collect();
+}
+
+/// The last cursor position converted to world space, which
+/// [`follow_cursor`] keeps easing toward when no new `MouseMove` samples are
+/// queued. Also tracks whether the cursor is
+/// currently within the bounds of a known monitor, to detect the
+/// `CursorEntered`/`CursorLeft` transition.
+#[derive(Resource)]
+struct CursorPosition {
+    world: Vec2,
+    on_screen: bool,
+}
+
+impl Default for CursorPosition {
+    fn default() -> Self {
+        Self {
+            world: Vec2::ZERO,
+            on_screen: true,
+        }
+    }
+}
+
+/// How long the scroll indicator takes to fade out after the last scroll
+/// event, after which it is hidden and reset.
+const SCROLL_IDLE_SECONDS: f32 = 0.6;
+/// Clamp on the vertical offset, in pixels, so a long scroll burst doesn't
+/// push the indicator arbitrarily far from the cursor.
+const SCROLL_OFFSET_MAX: f32 = 48.0;
+
+#[derive(Component)]
+struct ScrollIndicator {
+    /// Net accumulated scroll delta since the indicator last faded out,
+    /// offsetting the icon above or below the cursor.
+    accumulated: f32,
+    idle: Timer,
+}
+
+impl Default for ScrollIndicator {
+    fn default() -> Self {
+        Self {
+            accumulated: 0.0,
+            idle: Timer::from_seconds(SCROLL_IDLE_SECONDS, TimerMode::Once),
+        }
+    }
+}
+
 #[derive(Resource)]
 struct IndicatorAssets {
     sheet: Handle<Image>,
@@ -138,85 +666,292 @@ impl FromWorld for IndicatorAssets {
 }
 
 impl IndicatorAssets {
-    fn left(&self) -> Sprite {
+    fn sprite_for(&self, rect: IndicatorRect) -> Sprite {
+        Sprite {
+            image: self.sheet.clone(),
+            rect: Some(Rect::from_corners(
+                Vec2::new(rect.min.0, rect.min.1),
+                Vec2::new(rect.max.0, rect.max.1),
+            )),
+            ..default()
+        }
+    }
+
+    fn scroll(&self) -> Sprite {
         Sprite {
             image: self.sheet.clone(),
-            rect: Some(Rect::from_corners(Vec2::ZERO, Vec2::new(64.0, 64.0))),
+            rect: Some(Rect::from_corners(
+                Vec2::new(128.0, 0.0),
+                Vec2::new(192.0, 64.0),
+            )),
             ..default()
         }
     }
-    fn right(&self) -> Sprite {
+
+    /// Looks up the rect for one of an action indicator's extra animation
+    /// frames. The base row (y 0..64) holds every action's resting icon
+    /// side by side; each action's ripple frames live in additional rows
+    /// directly below its own base rect, at the same x range, so frame
+    /// lookups never wander into a neighbouring action's column. This means
+    /// `indicators.png` must be at least
+    /// `64 * (1 + PRESS_FRAME_COUNT)` px tall for the frames to exist.
+    fn action_frame(&self, base: IndicatorRect, frame: usize) -> Sprite {
+        let height = base.max.1 - base.min.1;
+        let offset = height * (frame + 1) as f32;
+
         Sprite {
             image: self.sheet.clone(),
             rect: Some(Rect::from_corners(
-                Vec2::new(64.0, 0.0),
-                Vec2::new(128.0, 64.0),
+                Vec2::new(base.min.0, base.min.1 + offset),
+                Vec2::new(base.max.0, base.max.1 + offset),
+            )),
+            ..default()
+        }
+    }
+
+    fn trail(&self) -> Sprite {
+        Sprite {
+            image: self.sheet.clone(),
+            rect: Some(Rect::from_corners(
+                Vec2::new(192.0, 0.0),
+                Vec2::new(256.0, 64.0),
             )),
             ..default()
         }
     }
 }
 
-/// true for left
 #[derive(Component)]
-struct MouseIndicator(bool);
+struct MouseIndicator(IndicatorAction);
+
+/// Whether this indicator's action is currently pressed, independent of its
+/// `Visibility` — the cursor entering/leaving the covered monitors toggles
+/// visibility without changing whether the action is actually held, so a
+/// `CursorEntered` needs this to know what to re-show.
+#[derive(Component)]
+struct ActionHeld(bool);
 
 #[derive(Debug)]
 enum MouseEvent {
-    LeftDown,
-    LeftUp,
-    RightDown,
-    RightUp,
+    ActionDown(IndicatorAction),
+    ActionUp(IndicatorAction),
     MouseMove(i32, i32),
+    /// Horizontal and vertical scroll wheel delta, as reported by `mki`.
+    Scroll(i32, i32),
+    /// The tracked cursor crossed back into the bounds of a covered
+    /// monitor, synthesized from `MouseMove` samples.
+    CursorEntered,
+    /// The tracked cursor left the bounds of every covered monitor,
+    /// synthesized from `MouseMove` samples.
+    CursorLeft,
 }
 
-#[derive(Resource, Debug)]
-struct GlobalMouseEventQueue(Arc<RwLock<VecDeque<MouseEvent>>>);
+/// An abstract action an indicator can react to (e.g. "primary",
+/// "secondary"), decoupled from whatever physical button or key triggers it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+struct IndicatorAction(String);
+
+/// A physical input that can drive an [`IndicatorAction`], as read from the
+/// bindings config.
+#[derive(Debug, Clone, Deserialize)]
+enum Trigger {
+    Mouse(MouseTrigger),
+    /// A keyboard key, named per [`keyboard_from_name`].
+    Key(String),
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+enum MouseTrigger {
+    Left,
+    Right,
+    Middle,
+}
+
+/// The sprite-sheet rect (in pixels) used to render an indicator's icon.
+#[derive(Debug, Clone, Copy, Deserialize)]
+struct IndicatorRect {
+    min: (f32, f32),
+    max: (f32, f32),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct Binding {
+    action: IndicatorAction,
+    trigger: Trigger,
+    rect: IndicatorRect,
+}
+
+/// Maps abstract [`IndicatorAction`]s to physical triggers and sprite rects,
+/// loaded from `assets/bindings.ron` at startup. Falls back to the classic
+/// left/right click bindings if the file is missing or malformed, so the
+/// overlay still works out of the box.
+#[derive(Resource, Debug, Clone, Deserialize)]
+struct Bindings(Vec<Binding>);
+
+impl Bindings {
+    const CONFIG_PATH: &'static str = "assets/bindings.ron";
+}
 
-impl Default for GlobalMouseEventQueue {
+impl Default for Bindings {
     fn default() -> Self {
-        let registry = Arc::new(RwLock::new(VecDeque::new()));
+        Self(vec![
+            Binding {
+                action: IndicatorAction("primary".into()),
+                trigger: Trigger::Mouse(MouseTrigger::Left),
+                rect: IndicatorRect {
+                    min: (0.0, 0.0),
+                    max: (64.0, 64.0),
+                },
+            },
+            Binding {
+                action: IndicatorAction("secondary".into()),
+                trigger: Trigger::Mouse(MouseTrigger::Right),
+                rect: IndicatorRect {
+                    min: (64.0, 0.0),
+                    max: (128.0, 64.0),
+                },
+            },
+        ])
+    }
+}
 
-        let left_registry = registry.clone();
-        Mouse::Left.act_on(Action {
-            callback: Box::new(move |_e, s| {
-                if s == mki::State::Released {
-                    trace!("Queueing left up");
-                    left_registry.write().unwrap().push_back(MouseEvent::LeftUp);
-                } else if s == mki::State::Pressed {
-                    trace!("Queueing left down");
-                    left_registry
-                        .write()
-                        .unwrap()
-                        .push_back(MouseEvent::LeftDown);
-                }
+impl FromWorld for Bindings {
+    fn from_world(_world: &mut World) -> Self {
+        match std::fs::read_to_string(Self::CONFIG_PATH) {
+            Ok(contents) => ron::from_str(&contents).unwrap_or_else(|err| {
+                warn!(
+                    "Failed to parse {}: {err}, falling back to default bindings",
+                    Self::CONFIG_PATH
+                );
+                Self::default()
             }),
-            inhibit: InhibitEvent::No,
-            defer: true,
-            sequencer: false,
-        });
+            Err(_) => Self::default(),
+        }
+    }
+}
 
-        let right_registry = registry.clone();
-        Mouse::Right.act_on(Action {
-            callback: Box::new(move |_e, s| {
-                if s == mki::State::Released {
-                    trace!("Queueing right up");
-                    right_registry
-                        .write()
-                        .unwrap()
-                        .push_back(MouseEvent::RightUp);
-                } else if s == mki::State::Pressed {
-                    trace!("Queueing right down");
-                    right_registry
-                        .write()
-                        .unwrap()
-                        .push_back(MouseEvent::RightDown);
-                }
+/// Looks up a named key against `mki`'s keyboard variants. Covers the
+/// letters A-Z plus the modifiers and whitespace keys used for indicator
+/// bindings; an unrecognized name (including digits, which aren't mapped
+/// here) returns `None` and the caller warns and skips that binding.
+fn keyboard_from_name(name: &str) -> Option<Keyboard> {
+    Some(match name {
+        "LeftControl" => Keyboard::LeftControl,
+        "RightControl" => Keyboard::RightControl,
+        "LeftShift" => Keyboard::LeftShift,
+        "RightShift" => Keyboard::RightShift,
+        "LeftAlt" => Keyboard::LeftAlt,
+        "RightAlt" => Keyboard::RightAlt,
+        "Space" => Keyboard::Space,
+        "Tab" => Keyboard::Tab,
+        "A" => Keyboard::A,
+        "B" => Keyboard::B,
+        "C" => Keyboard::C,
+        "D" => Keyboard::D,
+        "E" => Keyboard::E,
+        "F" => Keyboard::F,
+        "G" => Keyboard::G,
+        "H" => Keyboard::H,
+        "I" => Keyboard::I,
+        "J" => Keyboard::J,
+        "K" => Keyboard::K,
+        "L" => Keyboard::L,
+        "M" => Keyboard::M,
+        "N" => Keyboard::N,
+        "O" => Keyboard::O,
+        "P" => Keyboard::P,
+        "Q" => Keyboard::Q,
+        "R" => Keyboard::R,
+        "S" => Keyboard::S,
+        "T" => Keyboard::T,
+        "U" => Keyboard::U,
+        "V" => Keyboard::V,
+        "W" => Keyboard::W,
+        "X" => Keyboard::X,
+        "Y" => Keyboard::Y,
+        "Z" => Keyboard::Z,
+        _ => return None,
+    })
+}
+
+#[derive(Resource, Debug)]
+struct GlobalMouseEventQueue(Arc<RwLock<VecDeque<MouseEvent>>>);
+
+impl GlobalMouseEventQueue {
+    /// Builds the press/release callback for a single binding. Generic over
+    /// the triggering event type so the same logic drives both `Mouse` and
+    /// `Keyboard` triggers, which `mki` represents as distinct `act_on`
+    /// event types.
+    fn callback_for<T>(
+        registry: &Arc<RwLock<VecDeque<MouseEvent>>>,
+        action: IndicatorAction,
+    ) -> Box<dyn FnMut(T, mki::State) + Send + Sync>
+    where
+        T: 'static,
+    {
+        let down_registry = registry.clone();
+        let up_registry = registry.clone();
+        let down_action = action.clone();
+        let up_action = action;
+
+        Box::new(move |_e: T, s| {
+            if s == mki::State::Released {
+                trace!("Queueing {up_action:?} up");
+                up_registry
+                    .write()
+                    .unwrap()
+                    .push_back(MouseEvent::ActionUp(up_action.clone()));
+            } else if s == mki::State::Pressed {
+                trace!("Queueing {down_action:?} down");
+                down_registry
+                    .write()
+                    .unwrap()
+                    .push_back(MouseEvent::ActionDown(down_action.clone()));
+            }
+        })
+    }
+
+    fn register(registry: &Arc<RwLock<VecDeque<MouseEvent>>>, binding: &Binding) {
+        match &binding.trigger {
+            Trigger::Mouse(MouseTrigger::Left) => Mouse::Left.act_on(Action {
+                callback: Self::callback_for(registry, binding.action.clone()),
+                inhibit: InhibitEvent::No,
+                defer: true,
+                sequencer: false,
             }),
-            inhibit: InhibitEvent::No,
-            defer: true,
-            sequencer: false,
-        });
+            Trigger::Mouse(MouseTrigger::Right) => Mouse::Right.act_on(Action {
+                callback: Self::callback_for(registry, binding.action.clone()),
+                inhibit: InhibitEvent::No,
+                defer: true,
+                sequencer: false,
+            }),
+            Trigger::Mouse(MouseTrigger::Middle) => Mouse::Middle.act_on(Action {
+                callback: Self::callback_for(registry, binding.action.clone()),
+                inhibit: InhibitEvent::No,
+                defer: true,
+                sequencer: false,
+            }),
+            Trigger::Key(name) => match keyboard_from_name(name) {
+                Some(key) => key.act_on(Action {
+                    callback: Self::callback_for(registry, binding.action.clone()),
+                    inhibit: InhibitEvent::No,
+                    defer: true,
+                    sequencer: false,
+                }),
+                None => warn!("Unknown key binding {name:?}, skipping"),
+            },
+        }
+    }
+}
+
+impl FromWorld for GlobalMouseEventQueue {
+    fn from_world(world: &mut World) -> Self {
+        let bindings = world.resource::<Bindings>();
+        let registry = Arc::new(RwLock::new(VecDeque::new()));
+
+        for binding in &bindings.0 {
+            Self::register(&registry, binding);
+        }
 
         let move_registry = registry.clone();
         Mouse::track(move |x, y| {
@@ -226,6 +961,14 @@ impl Default for GlobalMouseEventQueue {
                 .push_back(MouseEvent::MouseMove(x, y));
         });
 
-        Self(registry.clone())
+        let scroll_registry = registry.clone();
+        Mouse::track_scroll(move |dx, dy| {
+            scroll_registry
+                .write()
+                .unwrap()
+                .push_back(MouseEvent::Scroll(dx, dy));
+        });
+
+        Self(registry)
     }
 }